@@ -19,6 +19,7 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 use std::ops::Deref;
 
+use http::header::{HeaderMap, HeaderName, HeaderValue};
 #[cfg(feature = "local-attributes")]
 use serde::{Deserialize, Serialize};
 
@@ -100,6 +101,54 @@ impl Deref for AttributeValue {
     }
 }
 
+/// A cloud provider's convention for mapping [`Attribute`] to and from HTTP headers
+///
+/// Each backend prefixes user-defined [`Attribute::Metadata`] differently, see
+/// [`Attributes::as_headers`] and [`Attributes::from_headers`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum AttributeProvider {
+    /// Amazon S3, using the `x-amz-meta-` metadata prefix
+    S3,
+    /// Google Cloud Storage, using the `x-goog-meta-` metadata prefix
+    GCS,
+    /// Azure Blob Storage, using the `x-ms-meta-` metadata prefix
+    Azure,
+}
+
+impl AttributeProvider {
+    /// The prefix used for [`Attribute::Metadata`] by this provider
+    fn metadata_prefix(&self) -> &'static str {
+        match self {
+            Self::S3 => "x-amz-meta-",
+            Self::GCS => "x-goog-meta-",
+            Self::Azure => "x-ms-meta-",
+        }
+    }
+
+    /// The prefix used to identify headers belonging to this provider
+    fn header_prefix(&self) -> &'static str {
+        match self {
+            Self::S3 => "x-amz-",
+            Self::GCS => "x-goog-",
+            Self::Azure => "x-ms-",
+        }
+    }
+}
+
+/// Returns the [`HeaderName`] for a well-known [`Attribute`], or `None` if
+/// it has no HTTP header equivalent
+fn well_known_header_name(attribute: &Attribute) -> Option<HeaderName> {
+    match attribute {
+        Attribute::ContentDisposition => Some(HeaderName::from_static("content-disposition")),
+        Attribute::ContentEncoding => Some(HeaderName::from_static("content-encoding")),
+        Attribute::ContentLanguage => Some(HeaderName::from_static("content-language")),
+        Attribute::ContentType => Some(HeaderName::from_static("content-type")),
+        Attribute::CacheControl => Some(HeaderName::from_static("cache-control")),
+        Attribute::Metadata(_) | Attribute::ProviderSpecific(_) => None,
+    }
+}
+
 /// Additional attributes of an object
 ///
 /// Attributes can be specified in [PutOptions](crate::PutOptions) and retrieved
@@ -165,6 +214,63 @@ impl Attributes {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Returns an [`Iterator`] of the HTTP headers representing this [`Attributes`]
+    /// for the given `provider`
+    ///
+    /// [`Attribute::Metadata`] is prefixed according to `provider`, e.g.
+    /// `x-amz-meta-` for [`AttributeProvider::S3`]. [`Attribute::ProviderSpecific`]
+    /// is passed through verbatim, as its key is already a header name
+    pub fn as_headers(
+        &self,
+        provider: AttributeProvider,
+    ) -> impl Iterator<Item = (HeaderName, HeaderValue)> + '_ {
+        self.iter_set_values().filter_map(move |(attribute, value)| {
+            let name = match attribute {
+                Attribute::Metadata(key) => {
+                    HeaderName::from_bytes(format!("{}{key}", provider.metadata_prefix()).as_bytes())
+                        .ok()?
+                }
+                Attribute::ProviderSpecific(key) => HeaderName::from_bytes(key.as_bytes()).ok()?,
+                _ => well_known_header_name(attribute)?,
+            };
+            let value = HeaderValue::from_str(value.as_ref()).ok()?;
+            Some((name, value))
+        })
+    }
+
+    /// Reconstructs an [`Attributes`] from the headers of a response, reversing
+    /// [`Attributes::as_headers`] for the given `provider`
+    ///
+    /// Headers that are neither well-known nor start with the provider's
+    /// header prefix (e.g. `x-amz-` for [`AttributeProvider::S3`]) are skipped,
+    /// so an [`Attribute::ProviderSpecific`] whose key lacks that prefix is not
+    /// reconstructed
+    pub fn from_headers(headers: &HeaderMap, provider: AttributeProvider) -> Self {
+        let mut attributes = Self::new();
+        for (name, value) in headers {
+            let Ok(value) = value.to_str() else {
+                continue;
+            };
+            let name = name.as_str();
+            let attribute = match name {
+                "content-disposition" => Attribute::ContentDisposition,
+                "content-encoding" => Attribute::ContentEncoding,
+                "content-language" => Attribute::ContentLanguage,
+                "content-type" => Attribute::ContentType,
+                "cache-control" => Attribute::CacheControl,
+                _ => match name.strip_prefix(provider.metadata_prefix()) {
+                    Some(key) => Attribute::Metadata(Cow::Owned(key.to_string())),
+                    None if name.starts_with(provider.header_prefix()) => {
+                        Attribute::ProviderSpecific(Cow::Owned(name.to_string()))
+                    }
+                    None => continue,
+                },
+            };
+            attributes.insert(attribute, Some(value.to_string().into()));
+        }
+        attributes
+    }
 }
 
 impl<K, V> FromIterator<(K, V)> for Attributes
@@ -249,4 +355,49 @@ mod tests {
             Some(&Some("value1".into()))
         );
     }
+
+    #[test]
+    fn test_as_headers() {
+        let attributes = Attributes::from_iter([
+            (Attribute::ContentDisposition, Some("inline".into())),
+            (Attribute::ContentType, Some("application/json".into())),
+            (Attribute::Metadata("my-key".into()), Some("my-value".into())),
+        ]);
+
+        let headers: HashMap<_, _> = attributes.as_headers(AttributeProvider::S3).collect();
+        assert_eq!(
+            headers.get(&HeaderName::from_static("content-disposition")),
+            Some(&HeaderValue::from_static("inline"))
+        );
+        assert_eq!(
+            headers.get(&HeaderName::from_static("content-type")),
+            Some(&HeaderValue::from_static("application/json"))
+        );
+        assert_eq!(
+            headers.get(&HeaderName::from_bytes(b"x-amz-meta-my-key").unwrap()),
+            Some(&HeaderValue::from_static("my-value"))
+        );
+
+        let headers: HashMap<_, _> = attributes.as_headers(AttributeProvider::Azure).collect();
+        assert_eq!(
+            headers.get(&HeaderName::from_bytes(b"x-ms-meta-my-key").unwrap()),
+            Some(&HeaderValue::from_static("my-value"))
+        );
+    }
+
+    #[test]
+    fn test_from_headers_roundtrip() {
+        let attributes = Attributes::from_iter([
+            (Attribute::CacheControl, Some("no-cache".into())),
+            (Attribute::Metadata("my-key".into()), Some("my-value".into())),
+        ]);
+
+        let mut headers = HeaderMap::new();
+        for (name, value) in attributes.as_headers(AttributeProvider::GCS) {
+            headers.insert(name, value);
+        }
+
+        let roundtripped = Attributes::from_headers(&headers, AttributeProvider::GCS);
+        assert_eq!(roundtripped, attributes);
+    }
 }
@@ -16,13 +16,33 @@
 
 //! The list and multipart API used by both GCS and S3
 
+use crate::attributes::{Attribute, Attributes};
 use crate::multipart::PartId;
 use crate::path::Path;
 use crate::{Error, ListResult, ObjectMeta, Result};
 use chrono::{DateTime, Utc};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::collections::HashMap;
 
+/// Characters percent-encoded in an `x-amz-tagging` key or value: everything
+/// except ASCII letters, digits and `+ - = . _ : / @`, the characters other
+/// than space that tag keys/values may contain. Space is encoded as `%20`
+const TAG_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'+')
+    .remove(b'-')
+    .remove(b'=')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b':')
+    .remove(b'/')
+    .remove(b'@');
+
+fn percent_encode(s: &str) -> impl std::fmt::Display + '_ {
+    utf8_percent_encode(s, TAG_ENCODE_SET)
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct ListResponse {
@@ -63,6 +83,16 @@ pub struct ListPrefix {
     pub prefix: String,
 }
 
+/// The owner of an object, as returned by `ListObjectsV2` when requested
+/// with `fetch-owner`
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Owner {
+    pub display_name: Option<String>,
+    #[serde(rename = "ID")]
+    pub id: String,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct ListContents {
@@ -71,12 +101,80 @@ pub struct ListContents {
     pub last_modified: DateTime<Utc>,
     #[serde(rename = "ETag")]
     pub e_tag: Option<String>,
+    #[serde(default)]
+    pub storage_class: Option<String>,
+    #[serde(default)]
+    pub owner: Option<Owner>,
+    #[serde(default, rename = "ChecksumAlgorithm")]
+    pub checksum_algorithm: Vec<String>,
+    #[serde(default, rename = "ChecksumCRC32")]
+    pub checksum_crc32: Option<String>,
+    #[serde(default, rename = "ChecksumCRC32C")]
+    pub checksum_crc32_c: Option<String>,
+    #[serde(default, rename = "ChecksumSHA1")]
+    pub checksum_sha1: Option<String>,
+    #[serde(default, rename = "ChecksumSHA256")]
+    pub checksum_sha256: Option<String>,
+}
+
+/// Collects the `StorageClass`, `Owner` and checksum value fields of a
+/// [`ListContents`] into an [`Attributes`] bag; `ChecksumAlgorithm` is not
+/// included. It is called only from this module's tests, and the
+/// `TryFrom<ListContents> for ObjectMeta` impl below does not use it.
+///
+/// Each entry is keyed by an `x-amz-*` header name, matching the
+/// [`Attribute::ProviderSpecific`] contract that the key is already a header
+/// name (see [`Attributes::as_headers`](crate::attributes::Attributes::as_headers)),
+/// so this bag round-trips through `as_headers`/`from_headers` with
+/// `AttributeProvider::S3`
+#[allow(dead_code)]
+fn extra_attributes(value: &ListContents) -> Attributes {
+    let mut extra = Attributes::new();
+    if let Some(storage_class) = &value.storage_class {
+        extra.insert(
+            Attribute::ProviderSpecific(Cow::Borrowed("x-amz-storage-class")),
+            Some(storage_class.clone().into()),
+        );
+    }
+    if let Some(owner) = &value.owner {
+        extra.insert(
+            Attribute::ProviderSpecific(Cow::Borrowed("x-amz-owner-id")),
+            Some(owner.id.clone().into()),
+        );
+        if let Some(display_name) = &owner.display_name {
+            extra.insert(
+                Attribute::ProviderSpecific(Cow::Borrowed("x-amz-owner-display-name")),
+                Some(display_name.clone().into()),
+            );
+        }
+    }
+    // Each algorithm keeps its own header key: an object can carry more than
+    // one checksum simultaneously, and collapsing them would both discard all
+    // but one and lose which algorithm the surviving value belongs to.
+    let checksums: [(&'static str, &Option<String>); 4] = [
+        ("x-amz-checksum-crc32", &value.checksum_crc32),
+        ("x-amz-checksum-crc32c", &value.checksum_crc32_c),
+        ("x-amz-checksum-sha1", &value.checksum_sha1),
+        ("x-amz-checksum-sha256", &value.checksum_sha256),
+    ];
+    for (key, checksum) in checksums {
+        if let Some(checksum) = checksum {
+            extra.insert(
+                Attribute::ProviderSpecific(Cow::Borrowed(key)),
+                Some(checksum.clone().into()),
+            );
+        }
+    }
+    extra
 }
 
 impl TryFrom<ListContents> for ObjectMeta {
     type Error = crate::Error;
 
     fn try_from(value: ListContents) -> Result<Self> {
+        // `ObjectMeta` has no field for the `storage_class`/`owner`/checksum
+        // data that `extra_attributes` above collects, so that data is not
+        // carried into the result.
         Ok(Self {
             location: Path::parse(value.key)?,
             last_modified: value.last_modified,
@@ -169,7 +267,39 @@ impl From<Tagging> for HashMap<String, String> {
     }
 }
 
+/// Parses the XML body of an S3 `GetObjectTagging` (`GET ?tagging`) response
+/// into a map from tag key to tag value
+///
+/// It is called only from this module's tests.
+#[allow(dead_code)]
+pub(crate) fn parse_get_tagging_response(body: &str) -> Result<HashMap<String, String>> {
+    let tagging: Tagging = quick_xml::de::from_str(body).map_err(|e| Error::Generic {
+        store: "S3",
+        source: Box::new(e),
+    })?;
+    Ok(tagging.into())
+}
+
 impl Tagging {
+    /// Encodes this [`Tagging`] as a `key=value&key=value` query string, suitable
+    /// for use as the value of the `x-amz-tagging` header
+    ///
+    /// It is called only from this module's tests.
+    pub fn to_header_value(&self) -> String {
+        self.list
+            .tags
+            .iter()
+            .map(|tag| {
+                format!(
+                    "{}={}",
+                    percent_encode(&tag.key),
+                    percent_encode(&tag.value)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
     pub fn to_xml_document(&self) -> Result<String> {
         let body = quick_xml::se::to_string(self).map_err(|e| Error::Generic {
             store: "",
@@ -192,6 +322,49 @@ impl Tagging {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_list_contents_extra_fields() {
+        let body = r#"
+            <Contents>
+                <Key>foo.parquet</Key>
+                <Size>1024</Size>
+                <LastModified>2023-01-01T00:00:00.000Z</LastModified>
+                <ETag>"abc123"</ETag>
+                <StorageClass>GLACIER</StorageClass>
+                <Owner><ID>owner-id</ID><DisplayName>me</DisplayName></Owner>
+                <ChecksumAlgorithm>CRC32C</ChecksumAlgorithm>
+            </Contents>
+        "#;
+        let contents: ListContents = quick_xml::de::from_str(body).unwrap();
+        assert_eq!(contents.storage_class.as_deref(), Some("GLACIER"));
+        assert_eq!(
+            contents.owner,
+            Some(Owner {
+                display_name: Some("me".to_string()),
+                id: "owner-id".to_string(),
+            })
+        );
+        assert_eq!(contents.checksum_algorithm, vec!["CRC32C".to_string()]);
+
+        let extra = extra_attributes(&contents);
+        assert_eq!(
+            extra.get(&Attribute::ProviderSpecific(Cow::Borrowed(
+                "x-amz-storage-class"
+            ))),
+            Some(&Some("GLACIER".to_string().into()))
+        );
+        assert_eq!(
+            extra.get(&Attribute::ProviderSpecific(Cow::Borrowed("x-amz-owner-id"))),
+            Some(&Some("owner-id".to_string().into()))
+        );
+        assert_eq!(
+            extra.get(&Attribute::ProviderSpecific(Cow::Borrowed(
+                "x-amz-owner-display-name"
+            ))),
+            Some(&Some("me".to_string().into()))
+        );
+    }
+
     #[test]
     fn test_tagging() {
         let expected_xml = r#"<?xml version="1.0" encoding="utf-8"?><Tagging><TagSet><Tag><Key>key1</Key><Value>value1</Value></Tag><Tag><Key>key2</Key><Value>value2</Value></Tag></TagSet></Tagging>"#;
@@ -214,6 +387,32 @@ mod tests {
         assert_eq!(body, expected_xml);
     }
 
+    #[test]
+    fn test_tagging_header_value() {
+        let tags = Tagging {
+            list: TagList {
+                tags: vec![
+                    Tag {
+                        key: "key1".to_string(),
+                        value: "value 1".to_string(),
+                    },
+                    Tag {
+                        key: "key2".to_string(),
+                        value: "value2".to_string(),
+                    },
+                ],
+            },
+        };
+        assert_eq!(tags.to_header_value(), "key1=value%201&key2=value2");
+    }
+
+    #[test]
+    fn test_parse_get_tagging_response() {
+        let body = r#"<?xml version="1.0" encoding="utf-8"?><Tagging><TagSet><Tag><Key>key1</Key><Value>value1</Value></Tag></TagSet></Tagging>"#;
+        let tags = parse_get_tagging_response(body).unwrap();
+        assert_eq!(tags.get("key1"), Some(&"value1".to_string()));
+    }
+
     #[test]
     fn test_tagging_azure() {
         let expected_xml = r#"<?xml version="1.0" encoding="utf-8"?><Tags><TagSet><Tag><Key>key1</Key><Value>value1</Value></Tag><Tag><Key>key2</Key><Value>value2</Value></Tag></TagSet></Tags>"#;